@@ -0,0 +1,52 @@
+"""Hatch build hook that records the git commit vkarious was built from."""
+
+from __future__ import annotations
+
+import subprocess
+from pathlib import Path
+
+from hatchling.builders.hooks.plugin.interface import BuildHookInterface
+
+BUILD_COMMIT_MODULE = Path("src") / "vkarious" / "_build_commit.py"
+
+
+class BuildCommitHook(BuildHookInterface):
+    """Write the source checkout's commit into `vkarious._build_commit`."""
+
+    PLUGIN_NAME = "custom"
+
+    def initialize(self, version: str, build_data: dict) -> None:
+        """Generate the commit module before files are collected."""
+        commit = self.detect_commit()
+        if commit is None:
+            # Building from an sdist: keep the module it already ships.
+            return
+        target = Path(self.root) / BUILD_COMMIT_MODULE
+        target.write_text(
+            '"""Generated at build time by hatch_build.py; do not edit."""\n'
+            "\n"
+            f'COMMIT = "{commit}"\n'
+        )
+
+    def detect_commit(self) -> str | None:
+        """Return `git describe` for the project root, or None outside a checkout."""
+        root = Path(self.root).resolve()
+        try:
+            toplevel = subprocess.run(
+                ["git", "rev-parse", "--show-toplevel"],
+                cwd=root,
+                check=True,
+                capture_output=True,
+                text=True,
+            ).stdout.strip()
+            if Path(toplevel).resolve() != root:
+                return None
+            return subprocess.run(
+                ["git", "describe", "--always", "--dirty"],
+                cwd=root,
+                check=True,
+                capture_output=True,
+                text=True,
+            ).stdout.strip() or None
+        except (OSError, subprocess.CalledProcessError):
+            return None
@@ -0,0 +1,50 @@
+"""Version and build metadata for vkarious."""
+
+from __future__ import annotations
+
+import importlib
+from importlib import metadata
+
+from . import __version__
+
+UNKNOWN = "unknown"
+
+
+class BuildInfo:
+    """Versions of vkarious, its source checkout and the linked database driver."""
+
+    def __init__(self, version: str, commit: str, psycopg_version: str) -> None:
+        self.version = version
+        self.commit = commit
+        self.psycopg_version = psycopg_version
+
+    @classmethod
+    def collect(cls) -> BuildInfo:
+        """Gather build metadata from the running installation."""
+        return cls(__version__, cls.detect_commit(), cls.detect_psycopg_version())
+
+    @staticmethod
+    def detect_commit() -> str:
+        """Return the git commit recorded in `vkarious._build_commit` at build time.
+
+        The module is generated by the hatch build hook in `hatch_build.py`;
+        builds made outside a vkarious checkout don't have it and report
+        `unknown`.
+        """
+        try:
+            build_commit = importlib.import_module("vkarious._build_commit")
+        except ImportError:
+            return UNKNOWN
+        return getattr(build_commit, "COMMIT", UNKNOWN) or UNKNOWN
+
+    @staticmethod
+    def detect_psycopg_version() -> str:
+        """Return the installed psycopg distribution version."""
+        try:
+            return metadata.version("psycopg")
+        except metadata.PackageNotFoundError:
+            return UNKNOWN
+
+    def describe(self) -> str:
+        """Return the one-line version string shown by `--version` and `version`."""
+        return f"vkarious {self.version} (commit {self.commit}, psycopg {self.psycopg_version})"
@@ -3,11 +3,13 @@
 from __future__ import annotations
 
 import subprocess
+from pathlib import Path
 
 import click
 
-from . import __version__
+from .build_info import BuildInfo
 from .db import (
+    ConnectionSettings,
     copy_database_files,
     create_branch_database,
     create_snapshot_database,
@@ -22,12 +24,13 @@ from .db import (
     initialize_database,
     list_databases,
     log_branch_operation,
+    read_password_file,
     register_branch_database,
     register_snapshot_database,
     register_source_database,
     restore_database_from_snapshot,
+    use_connection_settings,
 )
-from .change_capture import ChangeCaptureInstaller
 
 
 def run_command(command: list[str]) -> None:
@@ -36,9 +39,63 @@ def run_command(command: list[str]) -> None:
     click.echo(result.stdout.strip())
 
 
+def stdin_is_terminal() -> bool:
+    """Return True if standard input is an interactive terminal."""
+    return click.get_text_stream("stdin").isatty()
+
+
+def read_password_stdin() -> str:
+    """Read the database password from standard input.
+
+    Prompts without echoing when stdin is a terminal, otherwise reads the
+    first line of piped input.
+    """
+    if stdin_is_terminal():
+        return click.prompt("Database password", hide_input=True, default="", show_default=False)
+    return click.get_text_stream("stdin").readline().rstrip("\r\n")
+
+
+def print_version(ctx: click.Context, param: click.Parameter, value: bool) -> None:
+    """Print version and build metadata for `--version` and exit."""
+    if not value or ctx.resilient_parsing:
+        return
+    click.echo(BuildInfo.collect().describe())
+    ctx.exit()
+
+
 @click.group()
-def cli() -> None:
+@click.option(
+    "--version",
+    is_flag=True,
+    expose_value=False,
+    is_eager=True,
+    callback=print_version,
+    help="Show version and build metadata and exit.",
+)
+@click.option(
+    "--password-file",
+    type=click.Path(exists=True, dir_okay=False, path_type=Path),
+    help="Read the database password from the first line of this file.",
+)
+@click.option(
+    "--password-stdin",
+    is_flag=True,
+    help="Read the database password from standard input.",
+)
+@click.pass_context
+def cli(ctx: click.Context, password_file: Path | None, password_stdin: bool) -> None:
     """Manage PostgreSQL database snapshots."""
+    if password_file is not None and password_stdin:
+        raise click.UsageError("Use only one of --password-file and --password-stdin")
+    password = None
+    if password_file is not None:
+        password = read_password_file(password_file)
+    elif password_stdin:
+        password = read_password_stdin()
+    if password == "":
+        raise click.UsageError("The password read from --password-file or --password-stdin is empty")
+    if password is not None:
+        ctx.with_resource(use_connection_settings(ConnectionSettings(password=password)))
     initialize_database()
 
 
@@ -58,13 +115,6 @@ def branch(database_name: str, branch_name: str) -> None:
         register_source_database(database_name, source_oid)
         click.echo(f"Registered source database '{database_name}' in vka_databases")
         
-        # Ensure change-capture is installed on the source
-        installer = ChangeCaptureInstaller()
-        if installer.ensure_installed(database_name):
-            click.echo("Installed vkarious change-capture on source database")
-        else:
-            click.echo("vkarious change-capture already present on source database")
-
         # Get PostgreSQL data directory
         data_directory = get_data_directory()
         click.echo(f"PostgreSQL data directory: {data_directory}")
@@ -81,11 +131,6 @@ def branch(database_name: str, branch_name: str) -> None:
             copy_database_files(data_directory, source_oid, target_oid)
             click.echo("Database files copied successfully")
 
-        # Ensure change-capture is present on the new branch database as well
-        if installer.ensure_installed(branch_database_name):
-            click.echo("Installed vkarious change-capture on branch database")
-        else:
-            click.echo("vkarious change-capture already present on branch database")
         
         # Register branch database in vka_databases table
         register_branch_database(branch_database_name, target_oid, source_oid)
@@ -275,5 +320,5 @@ def list_databases_cmd() -> None:
 
 @cli.command()
 def version() -> None:
-    """Display the vkarious version."""
-    click.echo(__version__)
+    """Display the vkarious version and build metadata."""
+    click.echo(BuildInfo.collect().describe())
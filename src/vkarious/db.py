@@ -7,6 +7,7 @@ import re
 import subprocess
 import time
 from contextlib import contextmanager
+from contextvars import ContextVar
 from datetime import datetime
 from pathlib import Path
 from typing import Iterator
@@ -14,11 +15,87 @@ from typing import Iterator
 import psycopg
 
 
-def get_database_dsn() -> str:
-    """Get the database DSN from VKA_DATABASE environment variable."""
+LIBPQ_CONNECTION_ENV_VARS = ("PGSERVICE", "PGUSER", "PGDATABASE")
+
+
+class ConnectionSettings:
+    """Connection options supplied out-of-band instead of through VKA_DATABASE.
+
+    Keeps credentials such as a password read from a file out of the
+    environment and argv, where they would show up in process listings.
+    """
+
+    def __init__(self, password: str | None = None) -> None:
+        self.password = password
+
+    def apply(self, dsn: str) -> str:
+        """Return DSN with these settings merged in, overriding its own values."""
+        if self.password is None:
+            return dsn
+        conn_params = psycopg.conninfo.conninfo_to_dict(dsn)
+        conn_params['password'] = self.password
+        return psycopg.conninfo.make_conninfo(**conn_params)
+
+
+_active_settings: ContextVar[ConnectionSettings | None] = ContextVar(
+    "vkarious_connection_settings", default=None
+)
+
+
+@contextmanager
+def use_connection_settings(settings: ConnectionSettings) -> Iterator[None]:
+    """Apply SETTINGS to every connection opened inside the block."""
+    token = _active_settings.set(settings)
+    try:
+        yield
+    finally:
+        _active_settings.reset(token)
+
+
+def read_password_file(path: Path) -> str:
+    """Read a password from the first line of the file at PATH."""
+    content = path.read_text()
+    if not content:
+        return ""
+    return content.splitlines()[0]
+
+
+def has_libpq_environment() -> bool:
+    """Return True if the libpq environment variables describe a connection.
+
+    Any of `PGSERVICE`, `PGUSER` or `PGDATABASE` is enough; libpq fills in
+    the host (the local Unix socket) and port defaults like psql does.
+    `PGHOST`, `PGHOSTADDR`, `PGPORT` and `PGPASSWORD` are still honored but
+    are not enough on their own.
+    """
+    for name in LIBPQ_CONNECTION_ENV_VARS:
+        if os.getenv(name):
+            return True
+    return False
+
+
+def get_database_dsn(settings: ConnectionSettings | None = None) -> str:
+    """Get the database DSN from VKA_DATABASE environment variable.
+
+    When VKA_DATABASE is not set but the standard libpq variables describe
+    a connection (see `has_libpq_environment`), an empty DSN is returned so
+    libpq builds the connection from them, matching psql's behavior.
+
+    SETTINGS, or the ones active through `use_connection_settings`, are
+    merged into the DSN and override any password embedded in it.
+    """
     dsn = os.getenv("VKA_DATABASE")
     if not dsn:
-        raise ValueError("VKA_DATABASE environment variable is required")
+        if not has_libpq_environment():
+            raise ValueError(
+                "VKA_DATABASE is required, or set one of "
+                f"{', '.join(LIBPQ_CONNECTION_ENV_VARS)}"
+            )
+        dsn = ""
+    if settings is None:
+        settings = _active_settings.get()
+    if settings is not None:
+        dsn = settings.apply(dsn)
     return dsn
 
 
@@ -0,0 +1,77 @@
+import importlib.util
+import subprocess
+import sys
+import types
+from importlib import metadata
+from pathlib import Path
+
+import pytest
+
+from vkarious import __version__
+from vkarious import build_info
+from vkarious.build_info import BuildInfo
+
+HATCH_BUILD = Path(__file__).resolve().parent.parent / "hatch_build.py"
+
+
+def raise_package_not_found(name: str) -> str:
+    raise metadata.PackageNotFoundError(name)
+
+
+def raise_os_error(*args: object, **kwargs: object) -> None:
+    raise OSError("git not found")
+
+
+def raise_called_process_error(*args: object, **kwargs: object) -> None:
+    raise subprocess.CalledProcessError(128, "git")
+
+
+def load_hatch_build() -> types.ModuleType:
+    pytest.importorskip("hatchling")
+    spec = importlib.util.spec_from_file_location("hatch_build", HATCH_BUILD)
+    module = importlib.util.module_from_spec(spec)
+    spec.loader.exec_module(module)
+    return module
+
+
+def test_describe_includes_commit_and_driver_version() -> None:
+    info = BuildInfo("1.2.3", "abc1234", "3.2.9")
+    assert info.describe() == "vkarious 1.2.3 (commit abc1234, psycopg 3.2.9)"
+
+
+def test_collect_reads_commit_embedded_at_build_time(monkeypatch: pytest.MonkeyPatch) -> None:
+    build_commit = types.ModuleType("vkarious._build_commit")
+    build_commit.COMMIT = "abc1234"
+    monkeypatch.setitem(sys.modules, "vkarious._build_commit", build_commit)
+
+    assert BuildInfo.collect().commit == "abc1234"
+
+
+def test_collect_reports_unknown_without_build_metadata(monkeypatch: pytest.MonkeyPatch) -> None:
+    monkeypatch.setitem(sys.modules, "vkarious._build_commit", None)
+    monkeypatch.setattr(build_info.metadata, "version", raise_package_not_found)
+
+    description = BuildInfo.collect().describe()
+
+    assert description == f"vkarious {__version__} (commit unknown, psycopg unknown)"
+
+
+@pytest.mark.parametrize("failing_run", [raise_os_error, raise_called_process_error])
+def test_build_hook_skips_commit_without_git(
+    tmp_path: Path, monkeypatch: pytest.MonkeyPatch, failing_run: object
+) -> None:
+    hatch_build = load_hatch_build()
+    monkeypatch.setattr(hatch_build.subprocess, "run", failing_run)
+    hook = types.SimpleNamespace(root=str(tmp_path))
+
+    assert hatch_build.BuildCommitHook.detect_commit(hook) is None
+
+
+def test_build_hook_ignores_enclosing_repository(tmp_path: Path) -> None:
+    hatch_build = load_hatch_build()
+    subprocess.run(["git", "init", "-q", str(tmp_path)], check=True)
+    project = tmp_path / "vendor" / "vkarious"
+    project.mkdir(parents=True)
+    hook = types.SimpleNamespace(root=str(project))
+
+    assert hatch_build.BuildCommitHook.detect_commit(hook) is None